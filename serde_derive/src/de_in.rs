@@ -1,6 +1,6 @@
 //! Derive macro for `DeserializeIn` trait.
 
-use crate::internals::ast::{Container, Data, Field, Style};
+use crate::internals::ast::{Container, Data, Field, Style, Variant};
 use crate::internals::{replace_receiver, Ctxt, Derive};
 use crate::{dummy, private};
 use proc_macro2::{Span, TokenStream};
@@ -27,9 +27,7 @@ pub fn expand_derive_deserialize_in(input: &mut syn::DeriveInput) -> syn::Result
         Data::Struct(style, fields) => {
             deserialize_struct(*style, fields, alloc_param.as_ref(), &cont.ident)
         }
-        Data::Enum(_) => {
-            quote! { compile_error!("DeserializeIn for enums is not yet implemented") }
-        }
+        Data::Enum(variants) => deserialize_enum(variants, alloc_param.as_ref(), &cont.ident),
     };
 
     let impl_block = if let Some(ref alloc_ident) = alloc_param {
@@ -106,10 +104,194 @@ fn deserialize_struct(
 ) -> TokenStream {
     match style {
         Style::Struct => deserialize_struct_named(fields, alloc_param, struct_ident),
-        _ => quote! { compile_error!("Only named structs are supported for DeserializeIn") },
+        Style::Newtype => deserialize_struct_newtype(fields, alloc_param, struct_ident),
+        Style::Tuple => deserialize_struct_tuple(fields, alloc_param, struct_ident),
+        Style::Unit => {
+            quote! { compile_error!("Unit structs are not supported for DeserializeIn") }
+        }
+    }
+}
+
+fn deserialize_struct_newtype(
+    fields: &[Field],
+    alloc_param: Option<&Ident>,
+    struct_ident: &Ident,
+) -> TokenStream {
+    let alloc_param = match alloc_param {
+        Some(p) => p,
+        None => return quote! { compile_error!("alloc_param required") },
+    };
+
+    let struct_name_str = struct_ident.to_string();
+    let ty = &fields[0].ty;
+
+    quote! {
+        struct __Visitor<#alloc_param> {
+            __alloc: #alloc_param,
+        }
+
+        impl<'__de, #alloc_param> _serde::de::Visitor<'__de> for __Visitor<#alloc_param>
+        where
+            #alloc_param: ::core::alloc::Allocator + ::core::marker::Copy,
+        {
+            type Value = #struct_ident<#alloc_param>;
+
+            fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::fmt::Formatter::write_str(f, #struct_name_str)
+            }
+
+            fn visit_newtype_struct<__D>(self, __deserializer: __D) -> ::core::result::Result<Self::Value, __D::Error>
+            where
+                __D: _serde::Deserializer<'__de>,
+            {
+                let __value: #ty = _serde::de::DeserializeIn::deserialize_in(__deserializer, self.__alloc)?;
+                ::core::result::Result::Ok(#struct_ident(__value))
+            }
+        }
+
+        _serde::Deserializer::deserialize_newtype_struct(
+            __deserializer,
+            #struct_name_str,
+            __Visitor { __alloc: __alloc }
+        )
     }
 }
 
+fn deserialize_struct_tuple(
+    fields: &[Field],
+    alloc_param: Option<&Ident>,
+    struct_ident: &Ident,
+) -> TokenStream {
+    let alloc_param = match alloc_param {
+        Some(p) => p,
+        None => return quote! { compile_error!("alloc_param required") },
+    };
+
+    let struct_name_str = struct_ident.to_string();
+    let field_count = fields.len();
+    let expecting = format!(
+        "tuple struct {} with {} elements",
+        struct_name_str, field_count
+    );
+
+    let visit_seq_lets: Vec<TokenStream> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| {
+            let var = Ident::new(&format!("__field{}", i), Span::call_site());
+            let ty = &f.ty;
+            quote! {
+                let #var = match _serde::de::SeqAccess::next_element_seed(
+                    &mut __seq,
+                    __Seed::<#ty, #alloc_param> {
+                        alloc: self.__alloc.clone(),
+                        marker: ::core::marker::PhantomData,
+                    }
+                )? {
+                    ::core::option::Option::Some(__value) => __value,
+                    ::core::option::Option::None => {
+                        return ::core::result::Result::Err(_serde::de::Error::invalid_length(#i, &#expecting));
+                    }
+                };
+            }
+        })
+        .collect();
+
+    let field_vars: Vec<TokenStream> = (0..field_count)
+        .map(|i| Ident::new(&format!("__field{}", i), Span::call_site()))
+        .map(|var| quote! { #var })
+        .collect();
+
+    quote! {
+        struct __Seed<__T, __A> {
+            alloc: __A,
+            marker: ::core::marker::PhantomData<__T>,
+        }
+
+        impl<'__de, __T, __A> _serde::de::DeserializeSeed<'__de> for __Seed<__T, __A>
+        where
+            __T: _serde::de::DeserializeIn<'__de, __A>,
+            __A: ::core::alloc::Allocator + ::core::marker::Copy,
+        {
+            type Value = __T;
+
+            fn deserialize<__D>(self, deserializer: __D) -> ::core::result::Result<Self::Value, __D::Error>
+            where
+                __D: _serde::Deserializer<'__de>,
+            {
+                _serde::de::DeserializeIn::deserialize_in(deserializer, self.alloc)
+            }
+        }
+
+        struct __Visitor<#alloc_param> {
+            __alloc: #alloc_param,
+        }
+
+        impl<'__de, #alloc_param> _serde::de::Visitor<'__de> for __Visitor<#alloc_param>
+        where
+            #alloc_param: ::core::alloc::Allocator + ::core::marker::Copy,
+        {
+            type Value = #struct_ident<#alloc_param>;
+
+            fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::fmt::Formatter::write_str(f, #struct_name_str)
+            }
+
+            fn visit_seq<__A>(self, mut __seq: __A) -> ::core::result::Result<Self::Value, __A::Error>
+            where
+                __A: _serde::de::SeqAccess<'__de>,
+            {
+                #(#visit_seq_lets)*
+                ::core::result::Result::Ok(#struct_ident(#(#field_vars),*))
+            }
+        }
+
+        _serde::Deserializer::deserialize_tuple_struct(
+            __deserializer,
+            #struct_name_str,
+            #field_count,
+            __Visitor { __alloc: __alloc }
+        )
+    }
+}
+
+/// How a missing field should be filled in, per `#[serde(default)]` / `#[serde(default_in = "...")]`.
+enum FieldDefault {
+    None,
+    Default,
+    InPath(syn::ExprPath),
+}
+
+fn field_default(field: &Field) -> FieldDefault {
+    let mut default = FieldDefault::None;
+    for attr in &field.original.attrs {
+        if !attr.path().is_ident("serde") {
+            continue;
+        }
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default") {
+                default = FieldDefault::Default;
+            } else if meta.path.is_ident("default_in") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                default = FieldDefault::InPath(lit.parse()?);
+            } else if meta.input.peek(syn::Token![=]) {
+                // Unrelated `key = value` attribute (e.g. `rename = "..."`); consume just its
+                // value expression so parsing can continue to the next item in this
+                // `#[serde(...)]` list, instead of draining the rest of the stream.
+                let _ = meta.value()?.parse::<syn::Expr>()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                // Unrelated `key(...)` attribute (e.g. `rename(serialize = "...", deserialize = "...")`);
+                // consume the parenthesized group so parsing can continue past it.
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let _ = content.parse::<TokenStream>()?;
+            }
+            Ok(())
+        });
+    }
+    default
+}
+
 fn deserialize_struct_named(
     fields: &[Field],
     alloc_param: Option<&Ident>,
@@ -134,7 +316,9 @@ fn deserialize_struct_named(
                 .unwrap_or_else(|| Ident::new(&format!("__field{}", i), Span::call_site()));
             let name_str = f.attrs.name().deserialize_name().to_string();
             let ty = &f.ty;
-            (ident, name_str, ty)
+            let default = field_default(f);
+            let aliases: Vec<String> = f.attrs.aliases().map(|alias| alias.to_string()).collect();
+            (ident, name_str, ty, default, aliases)
         })
         .collect();
 
@@ -148,13 +332,37 @@ fn deserialize_struct_named(
         })
         .collect();
 
-    // Match arms for field names
+    // Match arms for field names, one `=>` arm per alias (aliases already include the primary name)
     let field_match_arms: Vec<TokenStream> = field_data
         .iter()
         .enumerate()
-        .map(|(i, (_, name_str, _))| {
+        .flat_map(|(i, (_, _, _, _, aliases))| {
             let variant = Ident::new(&format!("__Field{}", i), Span::call_site());
-            quote! { #name_str => ::core::result::Result::Ok(__Field::#variant) }
+            aliases.iter().map(
+                move |alias| quote! { #alias => ::core::result::Result::Ok(__Field::#variant) },
+            )
+        })
+        .collect();
+
+    // Match arms for numeric field identifiers (index-keyed formats like bincode)
+    let field_match_arms_u64: Vec<TokenStream> = (0..field_count)
+        .map(|i| {
+            let variant = Ident::new(&format!("__Field{}", i), Span::call_site());
+            let index = i as u64;
+            quote! { #index => ::core::result::Result::Ok(__Field::#variant) }
+        })
+        .collect();
+
+    // Match arms for byte-string field identifiers, one per alias
+    let field_match_arms_bytes: Vec<TokenStream> = field_data
+        .iter()
+        .enumerate()
+        .flat_map(|(i, (_, _, _, _, aliases))| {
+            let variant = Ident::new(&format!("__Field{}", i), Span::call_site());
+            aliases.iter().map(move |alias| {
+                let bytes = syn::LitByteStr::new(alias.as_bytes(), Span::call_site());
+                quote! { #bytes => ::core::result::Result::Ok(__Field::#variant) }
+            })
         })
         .collect();
 
@@ -170,7 +378,7 @@ fn deserialize_struct_named(
     let visit_map_arms: Vec<TokenStream> = field_data
         .iter()
         .enumerate()
-        .map(|(i, (ident, _, ty))| {
+        .map(|(i, (ident, _, ty, _, _))| {
             let variant = Ident::new(&format!("__Field{}", i), Span::call_site());
             let var = Ident::new(&format!("__field{}", i), Span::call_site());
             quote! {
@@ -196,18 +404,70 @@ fn deserialize_struct_named(
     let field_unwraps: Vec<TokenStream> = field_data
         .iter()
         .enumerate()
-        .map(|(i, (ident, name_str, _))| {
+        .map(|(i, (ident, name_str, _, default, _))| {
             let var = Ident::new(&format!("__field{}", i), Span::call_site());
-            quote! {
-                #ident: #var.ok_or_else(|| <__A::Error as _serde::de::Error>::missing_field(#name_str))?
-            }
+            let unwrap = match default {
+                FieldDefault::None => quote! {
+                    #var.ok_or_else(|| <__A::Error as _serde::de::Error>::missing_field(#name_str))?
+                },
+                FieldDefault::Default => quote! {
+                    #var.unwrap_or_else(::core::default::Default::default)
+                },
+                FieldDefault::InPath(path) => quote! {
+                    #var.unwrap_or_else(|| #path(self.__alloc.clone()))
+                },
+            };
+            quote! { #ident: #unwrap }
         })
         .collect();
 
     // Field names array
     let field_names_array: Vec<TokenStream> = field_data
         .iter()
-        .map(|(_, name_str, _)| quote! { #name_str })
+        .map(|(_, name_str, _, _, _)| quote! { #name_str })
+        .collect();
+
+    // visit_seq: pull each field from the sequence in declaration order, falling back to the
+    // field's default (if any) once the sequence runs out, same as `field_unwraps` does for maps.
+    let visit_seq_lets: Vec<TokenStream> = field_data
+        .iter()
+        .enumerate()
+        .map(|(i, (_, _, ty, default, _))| {
+            let var = Ident::new(&format!("__field{}", i), Span::call_site());
+            let expecting = format!("struct {} with {} elements", struct_name_str, field_count);
+            let none_arm = match default {
+                FieldDefault::None => quote! {
+                    return ::core::result::Result::Err(_serde::de::Error::invalid_length(#i, &#expecting));
+                },
+                FieldDefault::Default => quote! {
+                    ::core::default::Default::default()
+                },
+                FieldDefault::InPath(path) => quote! {
+                    #path(self.__alloc.clone())
+                },
+            };
+            quote! {
+                let #var = match _serde::de::SeqAccess::next_element_seed(
+                    &mut __seq,
+                    __Seed::<#ty, #alloc_param> {
+                        alloc: self.__alloc.clone(),
+                        marker: ::core::marker::PhantomData,
+                    }
+                )? {
+                    ::core::option::Option::Some(__value) => __value,
+                    ::core::option::Option::None => { #none_arm }
+                };
+            }
+        })
+        .collect();
+
+    let visit_seq_construct: Vec<TokenStream> = field_data
+        .iter()
+        .enumerate()
+        .map(|(i, (ident, _, _, _, _))| {
+            let var = Ident::new(&format!("__field{}", i), Span::call_site());
+            quote! { #ident: #var }
+        })
         .collect();
 
     quote! {
@@ -260,6 +520,26 @@ fn deserialize_struct_named(
                             _ => ::core::result::Result::Ok(__Field::__ignore),
                         }
                     }
+
+                    fn visit_u64<__E>(self, v: u64) -> ::core::result::Result<Self::Value, __E>
+                    where
+                        __E: _serde::de::Error,
+                    {
+                        match v {
+                            #(#field_match_arms_u64,)*
+                            _ => ::core::result::Result::Ok(__Field::__ignore),
+                        }
+                    }
+
+                    fn visit_bytes<__E>(self, v: &[u8]) -> ::core::result::Result<Self::Value, __E>
+                    where
+                        __E: _serde::de::Error,
+                    {
+                        match v {
+                            #(#field_match_arms_bytes,)*
+                            _ => ::core::result::Result::Ok(__Field::__ignore),
+                        }
+                    }
                 }
 
                 _serde::Deserializer::deserialize_identifier(__deserializer, __FieldVisitor)
@@ -299,6 +579,17 @@ fn deserialize_struct_named(
                     #(#field_unwraps,)*
                 })
             }
+
+            fn visit_seq<__A>(self, mut __seq: __A) -> ::core::result::Result<Self::Value, __A::Error>
+            where
+                __A: _serde::de::SeqAccess<'__de>,
+            {
+                #(#visit_seq_lets)*
+
+                ::core::result::Result::Ok(#struct_ident {
+                    #(#visit_seq_construct,)*
+                })
+            }
         }
 
         const __FIELDS: &[&str] = &[#(#field_names_array),*];
@@ -311,3 +602,567 @@ fn deserialize_struct_named(
         )
     }
 }
+
+fn deserialize_enum(
+    variants: &[Variant],
+    alloc_param: Option<&Ident>,
+    enum_ident: &Ident,
+) -> TokenStream {
+    let alloc_param = match alloc_param {
+        Some(p) => p,
+        None => return quote! { compile_error!("alloc_param required") },
+    };
+
+    let enum_name_str = enum_ident.to_string();
+
+    // Variant enum discriminators
+    let variant_enum_variants: Vec<TokenStream> = (0..variants.len())
+        .map(|i| {
+            let variant = Ident::new(&format!("__Variant{}", i), Span::call_site());
+            quote! { #variant }
+        })
+        .collect();
+
+    // Match arms for variant names
+    let variant_match_arms: Vec<TokenStream> = variants
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let variant = Ident::new(&format!("__Variant{}", i), Span::call_site());
+            let name_str = v.attrs.name().deserialize_name().to_string();
+            quote! { #name_str => ::core::result::Result::Ok(__Field::#variant) }
+        })
+        .collect();
+
+    let variant_names_array: Vec<TokenStream> = variants
+        .iter()
+        .map(|v| {
+            let name_str = v.attrs.name().deserialize_name().to_string();
+            quote! { #name_str }
+        })
+        .collect();
+
+    // Match arms for numeric variant identifiers (index-keyed formats like bincode)
+    let variant_match_arms_u64: Vec<TokenStream> = (0..variants.len())
+        .map(|i| {
+            let variant = Ident::new(&format!("__Variant{}", i), Span::call_site());
+            let index = i as u64;
+            quote! { #index => ::core::result::Result::Ok(__Field::#variant) }
+        })
+        .collect();
+
+    // Match arms for byte-string variant identifiers
+    let variant_match_arms_bytes: Vec<TokenStream> = variants
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let variant = Ident::new(&format!("__Variant{}", i), Span::call_site());
+            let name_str = v.attrs.name().deserialize_name().to_string();
+            let bytes = syn::LitByteStr::new(name_str.as_bytes(), Span::call_site());
+            quote! { #bytes => ::core::result::Result::Ok(__Field::#variant) }
+        })
+        .collect();
+
+    // One match arm (plus any supporting items) per variant kind
+    let mut support_items: Vec<TokenStream> = Vec::new();
+    let visit_enum_arms: Vec<TokenStream> = variants
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let discriminant = Ident::new(&format!("__Variant{}", i), Span::call_site());
+            let variant_ident = &v.original.ident;
+
+            match v.style {
+                Style::Unit => quote! {
+                    __Field::#discriminant => {
+                        _serde::de::VariantAccess::unit_variant(__variant)?;
+                        ::core::result::Result::Ok(#enum_ident::#variant_ident)
+                    }
+                },
+                Style::Newtype => {
+                    let ty = &v.fields[0].ty;
+                    quote! {
+                        __Field::#discriminant => {
+                            let __value = _serde::de::VariantAccess::newtype_variant_seed(
+                                __variant,
+                                __Seed::<#ty, #alloc_param> {
+                                    alloc: self.__alloc.clone(),
+                                    marker: ::core::marker::PhantomData,
+                                }
+                            )?;
+                            ::core::result::Result::Ok(#enum_ident::#variant_ident(__value))
+                        }
+                    }
+                }
+                Style::Tuple => {
+                    let tuple_visitor = Ident::new(&format!("__TupleVisitor{}", i), Span::call_site());
+                    let len = v.fields.len();
+                    let expecting = format!(
+                        "tuple variant {}::{} with {} elements",
+                        enum_name_str, variant_ident, len
+                    );
+
+                    let field_lets: Vec<TokenStream> = v
+                        .fields
+                        .iter()
+                        .enumerate()
+                        .map(|(j, field)| {
+                            let var = Ident::new(&format!("__field{}", j), Span::call_site());
+                            let ty = &field.ty;
+                            quote! {
+                                let #var = match _serde::de::SeqAccess::next_element_seed(
+                                    &mut __seq,
+                                    __Seed::<#ty, #alloc_param> {
+                                        alloc: self.__alloc.clone(),
+                                        marker: ::core::marker::PhantomData,
+                                    }
+                                )? {
+                                    ::core::option::Option::Some(__value) => __value,
+                                    ::core::option::Option::None => {
+                                        return ::core::result::Result::Err(_serde::de::Error::invalid_length(#j, &#expecting));
+                                    }
+                                };
+                            }
+                        })
+                        .collect();
+
+                    let field_vars: Vec<TokenStream> = (0..v.fields.len())
+                        .map(|j| {
+                            let var = Ident::new(&format!("__field{}", j), Span::call_site());
+                            quote! { #var }
+                        })
+                        .collect();
+
+                    support_items.push(quote! {
+                        struct #tuple_visitor<#alloc_param> {
+                            __alloc: #alloc_param,
+                        }
+
+                        impl<'__de, #alloc_param> _serde::de::Visitor<'__de> for #tuple_visitor<#alloc_param>
+                        where
+                            #alloc_param: ::core::alloc::Allocator + ::core::marker::Copy,
+                        {
+                            type Value = #enum_ident<#alloc_param>;
+
+                            fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                                ::core::fmt::Formatter::write_str(f, #expecting)
+                            }
+
+                            fn visit_seq<__A>(self, mut __seq: __A) -> ::core::result::Result<Self::Value, __A::Error>
+                            where
+                                __A: _serde::de::SeqAccess<'__de>,
+                            {
+                                #(#field_lets)*
+                                ::core::result::Result::Ok(#enum_ident::#variant_ident(#(#field_vars),*))
+                            }
+                        }
+                    });
+
+                    quote! {
+                        __Field::#discriminant => _serde::de::VariantAccess::tuple_variant(
+                            __variant,
+                            #len,
+                            #tuple_visitor { __alloc: self.__alloc.clone() }
+                        ),
+                    }
+                }
+                Style::Struct => {
+                    let struct_visitor = Ident::new(&format!("__StructVisitor{}", i), Span::call_site());
+                    let inner_field = Ident::new(&format!("__VField{}", i), Span::call_site());
+                    let inner_fields_const = Ident::new(&format!("__VFIELDS{}", i), Span::call_site());
+                    let variant_name_str = v.attrs.name().deserialize_name().to_string();
+
+                    let field_data: Vec<_> = v
+                        .fields
+                        .iter()
+                        .enumerate()
+                        .map(|(j, f)| {
+                            let ident = f
+                                .original
+                                .ident
+                                .clone()
+                                .unwrap_or_else(|| Ident::new(&format!("__field{}", j), Span::call_site()));
+                            let name_str = f.attrs.name().deserialize_name().to_string();
+                            let default = field_default(f);
+                            let aliases: Vec<String> =
+                                f.attrs.aliases().map(|alias| alias.to_string()).collect();
+                            (ident, name_str, &f.ty, default, aliases)
+                        })
+                        .collect();
+
+                    let inner_field_variants: Vec<TokenStream> = (0..field_data.len())
+                        .map(|j| {
+                            let variant = Ident::new(&format!("__VF{}", j), Span::call_site());
+                            quote! { #variant }
+                        })
+                        .collect();
+
+                    // One `=>` arm per alias (aliases already include the primary name), same as
+                    // the top-level named-struct field matching.
+                    let inner_match_arms: Vec<TokenStream> = field_data
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(j, (_, _, _, _, aliases))| {
+                            let variant = Ident::new(&format!("__VF{}", j), Span::call_site());
+                            aliases.iter().map(move |alias| {
+                                quote! { #alias => ::core::result::Result::Ok(#inner_field::#variant) }
+                            })
+                        })
+                        .collect();
+
+                    let inner_match_arms_u64: Vec<TokenStream> = (0..field_data.len())
+                        .map(|j| {
+                            let variant = Ident::new(&format!("__VF{}", j), Span::call_site());
+                            let index = j as u64;
+                            quote! { #index => ::core::result::Result::Ok(#inner_field::#variant) }
+                        })
+                        .collect();
+
+                    let inner_match_arms_bytes: Vec<TokenStream> = field_data
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(j, (_, _, _, _, aliases))| {
+                            let variant = Ident::new(&format!("__VF{}", j), Span::call_site());
+                            aliases.iter().map(move |alias| {
+                                let bytes = syn::LitByteStr::new(alias.as_bytes(), Span::call_site());
+                                quote! { #bytes => ::core::result::Result::Ok(#inner_field::#variant) }
+                            })
+                        })
+                        .collect();
+
+                    let field_names_array: Vec<TokenStream> = field_data
+                        .iter()
+                        .map(|(_, name_str, _, _, _)| quote! { #name_str })
+                        .collect();
+
+                    let field_vars_decl: Vec<TokenStream> = (0..field_data.len())
+                        .map(|j| {
+                            let var = Ident::new(&format!("__field{}", j), Span::call_site());
+                            quote! { let mut #var: ::core::option::Option<_> = ::core::option::Option::None; }
+                        })
+                        .collect();
+
+                    let visit_map_arms: Vec<TokenStream> = field_data
+                        .iter()
+                        .enumerate()
+                        .map(|(j, (ident, _, ty, _, _))| {
+                            let variant = Ident::new(&format!("__VF{}", j), Span::call_site());
+                            let var = Ident::new(&format!("__field{}", j), Span::call_site());
+                            quote! {
+                                #inner_field::#variant => {
+                                    if ::core::option::Option::is_some(&#var) {
+                                        return ::core::result::Result::Err(<__A::Error as _serde::de::Error>::duplicate_field(stringify!(#ident)));
+                                    }
+                                    #var = ::core::option::Option::Some(
+                                        _serde::de::MapAccess::next_value_seed(
+                                            &mut __map,
+                                            __Seed::<#ty, #alloc_param> {
+                                                alloc: self.__alloc.clone(),
+                                                marker: ::core::marker::PhantomData,
+                                            }
+                                        )?
+                                    );
+                                }
+                            }
+                        })
+                        .collect();
+
+                    let field_unwraps: Vec<TokenStream> = field_data
+                        .iter()
+                        .enumerate()
+                        .map(|(j, (ident, name_str, _, default, _))| {
+                            let var = Ident::new(&format!("__field{}", j), Span::call_site());
+                            let unwrap = match default {
+                                FieldDefault::None => quote! {
+                                    #var.ok_or_else(|| <__A::Error as _serde::de::Error>::missing_field(#name_str))?
+                                },
+                                FieldDefault::Default => quote! {
+                                    #var.unwrap_or_else(::core::default::Default::default)
+                                },
+                                FieldDefault::InPath(path) => quote! {
+                                    #var.unwrap_or_else(|| #path(self.__alloc.clone()))
+                                },
+                            };
+                            quote! { #ident: #unwrap }
+                        })
+                        .collect();
+
+                    let visit_seq_lets: Vec<TokenStream> = field_data
+                        .iter()
+                        .enumerate()
+                        .map(|(j, (_, _, ty, default, _))| {
+                            let var = Ident::new(&format!("__field{}", j), Span::call_site());
+                            let expecting = format!(
+                                "struct variant {}::{} with {} elements",
+                                enum_name_str, variant_ident, field_data.len()
+                            );
+                            let none_arm = match default {
+                                FieldDefault::None => quote! {
+                                    return ::core::result::Result::Err(_serde::de::Error::invalid_length(#j, &#expecting));
+                                },
+                                FieldDefault::Default => quote! {
+                                    ::core::default::Default::default()
+                                },
+                                FieldDefault::InPath(path) => quote! {
+                                    #path(self.__alloc.clone())
+                                },
+                            };
+                            quote! {
+                                let #var = match _serde::de::SeqAccess::next_element_seed(
+                                    &mut __seq,
+                                    __Seed::<#ty, #alloc_param> {
+                                        alloc: self.__alloc.clone(),
+                                        marker: ::core::marker::PhantomData,
+                                    }
+                                )? {
+                                    ::core::option::Option::Some(__value) => __value,
+                                    ::core::option::Option::None => { #none_arm }
+                                };
+                            }
+                        })
+                        .collect();
+
+                    let visit_seq_construct: Vec<TokenStream> = field_data
+                        .iter()
+                        .enumerate()
+                        .map(|(j, (ident, _, _, _, _))| {
+                            let var = Ident::new(&format!("__field{}", j), Span::call_site());
+                            quote! { #ident: #var }
+                        })
+                        .collect();
+
+                    support_items.push(quote! {
+                        #[allow(non_camel_case_types)]
+                        enum #inner_field {
+                            #(#inner_field_variants,)*
+                            __ignore,
+                        }
+
+                        impl<'__de> _serde::Deserialize<'__de> for #inner_field {
+                            fn deserialize<__D>(__deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+                            where
+                                __D: _serde::Deserializer<'__de>,
+                            {
+                                struct __FieldVisitor;
+
+                                impl<'__de> _serde::de::Visitor<'__de> for __FieldVisitor {
+                                    type Value = #inner_field;
+
+                                    fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                                        ::core::fmt::Formatter::write_str(f, "field identifier")
+                                    }
+
+                                    fn visit_str<__E>(self, v: &str) -> ::core::result::Result<Self::Value, __E>
+                                    where
+                                        __E: _serde::de::Error,
+                                    {
+                                        match v {
+                                            #(#inner_match_arms,)*
+                                            _ => ::core::result::Result::Ok(#inner_field::__ignore),
+                                        }
+                                    }
+
+                                    fn visit_u64<__E>(self, v: u64) -> ::core::result::Result<Self::Value, __E>
+                                    where
+                                        __E: _serde::de::Error,
+                                    {
+                                        match v {
+                                            #(#inner_match_arms_u64,)*
+                                            _ => ::core::result::Result::Ok(#inner_field::__ignore),
+                                        }
+                                    }
+
+                                    fn visit_bytes<__E>(self, v: &[u8]) -> ::core::result::Result<Self::Value, __E>
+                                    where
+                                        __E: _serde::de::Error,
+                                    {
+                                        match v {
+                                            #(#inner_match_arms_bytes,)*
+                                            _ => ::core::result::Result::Ok(#inner_field::__ignore),
+                                        }
+                                    }
+                                }
+
+                                _serde::Deserializer::deserialize_identifier(__deserializer, __FieldVisitor)
+                            }
+                        }
+
+                        struct #struct_visitor<#alloc_param> {
+                            __alloc: #alloc_param,
+                        }
+
+                        impl<'__de, #alloc_param> _serde::de::Visitor<'__de> for #struct_visitor<#alloc_param>
+                        where
+                            #alloc_param: ::core::alloc::Allocator + ::core::marker::Copy,
+                        {
+                            type Value = #enum_ident<#alloc_param>;
+
+                            fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                                ::core::fmt::Formatter::write_str(f, #variant_name_str)
+                            }
+
+                            fn visit_map<__A>(self, mut __map: __A) -> ::core::result::Result<Self::Value, __A::Error>
+                            where
+                                __A: _serde::de::MapAccess<'__de>,
+                            {
+                                #(#field_vars_decl)*
+
+                                while let ::core::option::Option::Some(__key) = _serde::de::MapAccess::next_key::<#inner_field>(&mut __map)? {
+                                    match __key {
+                                        #(#visit_map_arms)*
+                                        #inner_field::__ignore => {
+                                            let _ = _serde::de::MapAccess::next_value::<_serde::de::IgnoredAny>(&mut __map)?;
+                                        }
+                                    }
+                                }
+
+                                ::core::result::Result::Ok(#enum_ident::#variant_ident {
+                                    #(#field_unwraps,)*
+                                })
+                            }
+
+                            fn visit_seq<__A>(self, mut __seq: __A) -> ::core::result::Result<Self::Value, __A::Error>
+                            where
+                                __A: _serde::de::SeqAccess<'__de>,
+                            {
+                                #(#visit_seq_lets)*
+
+                                ::core::result::Result::Ok(#enum_ident::#variant_ident {
+                                    #(#visit_seq_construct,)*
+                                })
+                            }
+                        }
+
+                        const #inner_fields_const: &[&str] = &[#(#field_names_array),*];
+                    });
+
+                    quote! {
+                        __Field::#discriminant => _serde::de::VariantAccess::struct_variant(
+                            __variant,
+                            #inner_fields_const,
+                            #struct_visitor { __alloc: self.__alloc.clone() }
+                        ),
+                    }
+                }
+            }
+        })
+        .collect();
+
+    quote! {
+        struct __Seed<__T, __A> {
+            alloc: __A,
+            marker: ::core::marker::PhantomData<__T>,
+        }
+
+        impl<'__de, __T, __A> _serde::de::DeserializeSeed<'__de> for __Seed<__T, __A>
+        where
+            __T: _serde::de::DeserializeIn<'__de, __A>,
+            __A: ::core::alloc::Allocator + ::core::marker::Copy,
+        {
+            type Value = __T;
+
+            fn deserialize<__D>(self, deserializer: __D) -> ::core::result::Result<Self::Value, __D::Error>
+            where
+                __D: _serde::Deserializer<'__de>,
+            {
+                _serde::de::DeserializeIn::deserialize_in(deserializer, self.alloc)
+            }
+        }
+
+        #[allow(non_camel_case_types)]
+        enum __Field {
+            #(#variant_enum_variants,)*
+        }
+
+        impl<'__de> _serde::Deserialize<'__de> for __Field {
+            fn deserialize<__D>(__deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+            where
+                __D: _serde::Deserializer<'__de>,
+            {
+                struct __FieldVisitor;
+
+                impl<'__de> _serde::de::Visitor<'__de> for __FieldVisitor {
+                    type Value = __Field;
+
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        ::core::fmt::Formatter::write_str(f, "variant identifier")
+                    }
+
+                    fn visit_str<__E>(self, v: &str) -> ::core::result::Result<Self::Value, __E>
+                    where
+                        __E: _serde::de::Error,
+                    {
+                        match v {
+                            #(#variant_match_arms,)*
+                            _ => ::core::result::Result::Err(_serde::de::Error::unknown_variant(v, VARIANTS)),
+                        }
+                    }
+
+                    fn visit_u64<__E>(self, v: u64) -> ::core::result::Result<Self::Value, __E>
+                    where
+                        __E: _serde::de::Error,
+                    {
+                        match v {
+                            #(#variant_match_arms_u64,)*
+                            _ => ::core::result::Result::Err(_serde::de::Error::invalid_value(
+                                _serde::de::Unexpected::Unsigned(v),
+                                &"variant index 0 <= i < VARIANTS.len()",
+                            )),
+                        }
+                    }
+
+                    fn visit_bytes<__E>(self, v: &[u8]) -> ::core::result::Result<Self::Value, __E>
+                    where
+                        __E: _serde::de::Error,
+                    {
+                        match v {
+                            #(#variant_match_arms_bytes,)*
+                            _ => {
+                                let value = ::core::str::from_utf8(v).unwrap_or("\u{fffd}\u{fffd}\u{fffd}");
+                                ::core::result::Result::Err(_serde::de::Error::unknown_variant(value, VARIANTS))
+                            }
+                        }
+                    }
+                }
+
+                _serde::Deserializer::deserialize_identifier(__deserializer, __FieldVisitor)
+            }
+        }
+
+        #(#support_items)*
+
+        const VARIANTS: &[&str] = &[#(#variant_names_array),*];
+
+        struct __Visitor<#alloc_param> {
+            __alloc: #alloc_param,
+        }
+
+        impl<'__de, #alloc_param> _serde::de::Visitor<'__de> for __Visitor<#alloc_param>
+        where
+            #alloc_param: ::core::alloc::Allocator + ::core::marker::Copy,
+        {
+            type Value = #enum_ident<#alloc_param>;
+
+            fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::fmt::Formatter::write_str(f, #enum_name_str)
+            }
+
+            fn visit_enum<__A>(self, __data: __A) -> ::core::result::Result<Self::Value, __A::Error>
+            where
+                __A: _serde::de::EnumAccess<'__de>,
+            {
+                let (__field, __variant) = _serde::de::EnumAccess::variant::<__Field>(__data)?;
+                match __field {
+                    #(#visit_enum_arms)*
+                }
+            }
+        }
+
+        _serde::Deserializer::deserialize_enum(
+            __deserializer,
+            #enum_name_str,
+            VARIANTS,
+            __Visitor { __alloc: __alloc }
+        )
+    }
+}